@@ -0,0 +1,85 @@
+// This module holds the pure comparison and parsing logic for the game
+// Pulling it out of main means none of it depends on io, so it can be
+// exercised directly by the unit tests at the bottom of this file
+
+// Ordering is the enum returned by cmp, with the variants Less, Greater and Equal
+// We re-use it here so check_guess can hand the same three outcomes back to main
+use std::cmp::Ordering;
+
+// The two distinct ways a raw guess string can be rejected
+// NotANumber = the input did not parse as a whole number at all
+// OutOfRange = it parsed fine but fell outside the active secret-number range
+// Keeping them separate lets main print the right message for each case
+#[derive(Debug, PartialEq, Eq)]
+pub enum GuessError {
+    NotANumber,
+    OutOfRange,
+}
+
+// Compare a parsed guess against the secret number
+// This is just guess.cmp(&secret) lifted out of main so it can be tested
+// It returns a variant of Ordering: Less, Greater or Equal
+pub fn check_guess(guess: i64, secret: i64) -> Ordering {
+    guess.cmp(&secret)
+}
+
+// Turn the raw line the user typed into a validated guess
+// First trim the whitespace (including the trailing newline from read_line)
+// and parse it as an i64, mapping a failed parse to GuessError::NotANumber
+// Then reject anything outside low..=high as GuessError::OutOfRange
+// so the caller can keep the comparison path for valid guesses only
+pub fn parse_guess(input: &str, low: i64, high: i64) -> Result<i64, GuessError> {
+    let guess: i64 = input.trim().parse().map_err(|_| GuessError::NotANumber)?;
+
+    if guess < low || guess > high {
+        return Err(GuessError::OutOfRange);
+    }
+
+    Ok(guess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_guess_too_small() {
+        assert_eq!(check_guess(10, 50), Ordering::Less);
+    }
+
+    #[test]
+    fn check_guess_too_big() {
+        assert_eq!(check_guess(90, 50), Ordering::Greater);
+    }
+
+    #[test]
+    fn check_guess_equal() {
+        assert_eq!(check_guess(50, 50), Ordering::Equal);
+    }
+
+    #[test]
+    fn parse_guess_non_numeric() {
+        assert_eq!(parse_guess("abc", 1, 100), Err(GuessError::NotANumber));
+    }
+
+    #[test]
+    fn parse_guess_below_range() {
+        assert_eq!(parse_guess("0", 1, 100), Err(GuessError::OutOfRange));
+    }
+
+    #[test]
+    fn parse_guess_above_range() {
+        assert_eq!(parse_guess("101", 1, 100), Err(GuessError::OutOfRange));
+    }
+
+    #[test]
+    fn parse_guess_at_boundaries() {
+        assert_eq!(parse_guess("1", 1, 100), Ok(1));
+        assert_eq!(parse_guess("100", 1, 100), Ok(100));
+    }
+
+    #[test]
+    fn parse_guess_trims_whitespace() {
+        assert_eq!(parse_guess("  42\n", 1, 100), Ok(42));
+    }
+}
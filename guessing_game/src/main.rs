@@ -15,22 +15,55 @@ use rand::Rng;
 // Taking in account the 3 outcomes that are possible when comparing two values
 use std::cmp::Ordering;
 
+// The comparison and parsing logic now lives in the library crate
+// check_guess wraps guess.cmp(&secret) and parse_guess handles the trim/parse
+// plus the range check, returning a GuessError when the input is rejected
+use guessing_game::{check_guess, parse_guess, GuessError};
+
 fn main() {
     println!("Guess the number!");
-    
-    // Call rand::thread_rng function that gives the particular random no. generator 
+
+    // Before starting the game, ask the player to pick a difficulty level
+    // The difficulty only changes the range the secret number is drawn from:
+    // Easy 1..=10, Medium 1..=100, Hard 1..=1000
+    // We read the choice with the same io::stdin().read_line pattern used for guesses
+    println!("Choose a difficulty: (E)asy 1-10, (M)edium 1-100, (H)ard 1-1000.");
+    let mut difficulty = String::new();
+    io::stdin()
+        .read_line(&mut difficulty)
+        .expect("Failed to read line");
+
+    // Match on the trimmed choice to pick the inclusive bounds for the secret number
+    // The low and high bounds are kept in their own variables so they can be reused later
+    // e.g. when generating the number and when validating that a guess falls in range
+    // Anything we don't recognise falls through to the Medium default of 1..=100
+    let (low, high): (i64, i64) = match difficulty.trim() {
+        "E" | "e" => (1, 10),
+        "H" | "h" => (1, 1000),
+        _ => (1, 100),
+    };
+
+    // Call rand::thread_rng function that gives the particular random no. generator
     // one that is local to the current thread of execution and seeded by the operating system
     // then call gen_range method on the random no. generator
     // this method is defined by the Rng trait with the use rand::Rng statement
-    // gen_range method takes a range expression as an argument 
+    // gen_range method takes a range expression as an argument
     // and generates a random number in the range
     // the kind of range expression being used here takes the form of start..=end
-    // so need to specify 1..=100 to request a no. between 1 and 100
-    let secret_number = rand::thread_rng().gen_range(1..=100);
+    // feeding the difficulty-chosen low and high bounds requests a no. between them
+    let secret_number = rand::thread_rng().gen_range(low..=high);
 
     // Remove comment for line below to test program if needed
     // println!("The secret number is: {secret_number}");
     
+    // Track how many guesses the player has made so we can cap the attempts
+    // The counter is mutable because we bump it once per valid guess in the loop
+    let mut attempts = 0;
+
+    // The player only gets a limited number of guesses before losing
+    // Reaching this without an Ordering::Equal ends the game in a loss
+    let max_attempts = 7;
+
     // give users more chances at guessing the number by using a loop
     loop {
         println!("Please input your guess.");
@@ -102,11 +135,27 @@ fn main() {
         // If parse is NOT able to turn the string into a number, it'll reutrn an Err value
         // The underscore __ is a catchall value
         // The program will execute the second arm's code, continue, which tells the program to go to the next iteration of the loop and ask for another guess
-        let guess: u32 = match guess.trim().parse() {
+        // Hand the raw line to parse_guess, which trims and parses it as an i64
+        // and validates it against the active low..=high range
+        // The two GuessError variants map to the two messages we used to inline:
+        // NotANumber asks for a whole number, OutOfRange reports the valid range
+        let guess = match parse_guess(&guess, low, high) {
             Ok(num) => num,
-            Err(_) => continue,
+            Err(GuessError::NotANumber) => {
+                println!("Please enter a whole number!");
+                continue;
+            }
+            Err(GuessError::OutOfRange) => {
+                println!("Out of range ({low}\u{2013}{high})!");
+                continue;
+            }
         };
 
+        // Only a valid, in-range guess counts against the attempt budget
+        // Malformed or out-of-range input already took the continue paths above,
+        // so those never burn one of the limited attempts
+        attempts += 1;
+
         // Prints the string that now contains the user's input
         // The {} is a placeholder
         // Can print more than one value using {}
@@ -122,17 +171,28 @@ fn main() {
         // A match expression is made up of arms
         // Arm consists of a pattern to match against and the code that should be run if the value given to match fits that arm's pattern
         // Rust takes the value given to matchh and looks through each arm's pattern in turn
-        match guess.cmp(&secret_number) {
+        match check_guess(guess, secret_number) {
             Ordering::Less => println!("Too small!"),
             Ordering::Greater => println!("Too big!"),
             Ordering::Equal => {
-                println!("You win!");
+                // Report how many guesses it took to reach the secret number
+                // Pick the singular noun on a first-try win so it doesn't read "1 guesses"
+                let noun = if attempts == 1 { "guess" } else { "guesses" };
+                println!("You win! It took you {attempts} {noun}.");
                 // Make the program exits the loop when the user guesses the number correctly
                 break;
             }
 
         }
-        
+
+        // After a wrong guess, check whether the player has used up their attempts
+        // When the count reaches the maximum without a win, reveal the secret number
+        // and end the game in a loss instead of looping forever
+        if attempts >= max_attempts {
+            println!("You lose! The secret number was: {secret_number}");
+            break;
+        }
+
     }
     
 }